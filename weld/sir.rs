@@ -27,6 +27,12 @@ pub enum Statement {
     GetResult(Symbol, Symbol),
     /// output, builder type
     CreateBuilder(Symbol, Type),
+    /// builder being released
+    Free(Symbol),
+    /// output, field values
+    MakeStruct(Symbol, Vec<Symbol>),
+    /// output, struct value, field index
+    GetField(Symbol, Symbol, u32),
 }
 
 #[derive(Clone)]
@@ -143,6 +149,18 @@ impl fmt::Display for Statement {
             DoMerge(ref bld, ref elem) => write!(f, "merge {} {}", bld, elem),
             GetResult(ref out, ref value) => write!(f, "{} = result {}", out, value),
             CreateBuilder(ref out, ref ty) => write!(f, "{} = new {}", out, print_type(ty)),
+            Free(ref sym) => write!(f, "free {}", sym),
+            MakeStruct(ref out, ref elems) => {
+                write!(f, "{} = {{", out)?;
+                for (i, elem) in elems.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", elem)?;
+                }
+                write!(f, "}}")
+            },
+            GetField(ref out, ref value, index) => write!(f, "{} = {}.{}", out, value, index),
         }
     }
 }
@@ -208,10 +226,116 @@ impl fmt::Display for SirProgram {
     }
 }
 
+/// The symbol a statement (re)defines, if any -- `DoMerge` and `Free` only use their operands.
+fn statement_def(stmt: &Statement) -> Option<Symbol> {
+    use self::Statement::*;
+    match *stmt {
+        AssignBinOp(ref out, ..) => Some(out.clone()),
+        Assign(ref out, _) => Some(out.clone()),
+        AssignLiteral(ref out, _) => Some(out.clone()),
+        GetResult(ref out, _) => Some(out.clone()),
+        CreateBuilder(ref out, _) => Some(out.clone()),
+        MakeStruct(ref out, _) => Some(out.clone()),
+        GetField(ref out, _, _) => Some(out.clone()),
+        DoMerge(..) | Free(_) => None
+    }
+}
+
+/// The symbols a statement uses as operands.
+fn statement_uses(stmt: &Statement) -> Vec<Symbol> {
+    use self::Statement::*;
+    match *stmt {
+        AssignBinOp(_, _, _, ref left, ref right) => vec![left.clone(), right.clone()],
+        Assign(_, ref value) => vec![value.clone()],
+        AssignLiteral(..) => vec![],
+        DoMerge(ref bld, ref elem) => vec![bld.clone(), elem.clone()],
+        GetResult(_, ref value) => vec![value.clone()],
+        CreateBuilder(..) => vec![],
+        Free(ref sym) => vec![sym.clone()],
+        MakeStruct(_, ref elems) => elems.clone(),
+        GetField(_, ref value, _) => vec![value.clone()]
+    }
+}
+
+/// The within-function successor blocks of a terminator; `JumpFunction`/`ParallelFor` leave the
+/// function entirely, so they have none here (their effect on liveness is modeled separately,
+/// via each callee's own computed closure).
+fn terminator_successors(term: &Terminator) -> Vec<BasicBlockId> {
+    use self::Terminator::*;
+    match *term {
+        Branch(_, on_true, on_false) => vec![on_true, on_false],
+        JumpBlock(target) => vec![target],
+        JumpFunction(_) | ProgramReturn(_) | EndFunction | ParallelFor(_) | Crash => vec![]
+    }
+}
+
+/// The symbols a terminator itself uses directly (as opposed to what it hands off to a callee).
+fn terminator_local_uses(term: &Terminator) -> Vec<Symbol> {
+    use self::Terminator::*;
+    match *term {
+        Branch(ref cond, _, _) => vec![cond.clone()],
+        ProgramReturn(ref sym) => vec![sym.clone()],
+        ParallelFor(ref pf) => vec![pf.data.clone(), pf.builder.clone()],
+        JumpBlock(_) | JumpFunction(_) | EndFunction | Crash => vec![]
+    }
+}
+
+/// Solve `live_in = use ∪ (live_out − def)` to a fixpoint over `func`'s blocks. `extra_uses`
+/// supplies, per block, the symbols a `JumpFunction`/`ParallelFor` terminator hands to a callee
+/// (i.e. that callee's own closure) -- these are folded in as additional uses of that block, since
+/// from this function's point of view they're live right up until control leaves through that
+/// terminator.
+fn compute_live_in(func: &SirFunction, extra_uses: &HashMap<BasicBlockId, Vec<Symbol>>)
+-> Vec<HashSet<Symbol>> {
+    let n = func.blocks.len();
+    let mut live_in: Vec<HashSet<Symbol>> = vec![HashSet::new(); n];
+    let mut live_out: Vec<HashSet<Symbol>> = vec![HashSet::new(); n];
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for b in 0..n {
+            let block = &func.blocks[b];
+            let mut new_live_out = HashSet::new();
+            for succ in terminator_successors(&block.terminator) {
+                new_live_out.extend(live_in[succ].iter().cloned());
+            }
+            if new_live_out != live_out[b] {
+                live_out[b] = new_live_out;
+                changed = true;
+            }
+
+            let mut live = live_out[b].clone();
+            if let Some(extra) = extra_uses.get(&b) {
+                live.extend(extra.iter().cloned());
+            }
+            for sym in terminator_local_uses(&block.terminator) {
+                live.insert(sym);
+            }
+            for stmt in block.statements.iter().rev() {
+                if let Some(def) = statement_def(stmt) {
+                    live.remove(&def);
+                }
+                live.extend(statement_uses(stmt));
+            }
+            if live != live_in[b] {
+                live_in[b] = live;
+                changed = true;
+            }
+        }
+    }
+    live_in
+}
+
 /// Recursive helper function for sir_param_correction. env contains the symbol to type mappings
 /// that have been defined previously in the program. Any symbols that need to be passed in
 /// as closure parameters to func_id will be added to closure (so that func_id's
 /// callers can also add these symbols to their parameters list, if necessary).
+///
+/// Closures are minimized with a proper backward liveness analysis rather than conservatively
+/// threading every free variable a function's statements mention: callees are corrected first (so
+/// their own closures are known), those closures become the `use` contributed by the
+/// `JumpFunction`/`ParallelFor` terminator that reaches them, and a symbol only becomes one of
+/// func_id's own params if it's live-in at func_id's entry block (block 0).
 fn sir_param_correction_helper(prog: &mut SirProgram, func_id: FunctionId,
 env: &mut HashMap<Symbol, Type>, closure: &mut HashSet<Symbol>) {
     for (name, ty) in &prog.funcs[func_id].params {
@@ -221,34 +345,12 @@ env: &mut HashMap<Symbol, Type>, closure: &mut HashSet<Symbol>) {
         env.insert(name.clone(), ty.clone());
     }
     // All symbols are unique, so there is no need to remove stuff from env at any point.
+
+    let mut extra_uses: HashMap<BasicBlockId, Vec<Symbol>> = HashMap::new();
     for block in prog.funcs[func_id].blocks.clone() {
-        let mut vars = vec![];
-        for statement in &block.statements {
-            use self::Statement::*;
-            match *statement {
-                AssignBinOp(_, _, _, ref left, ref right) => {
-                    vars.push(left.clone());
-                    vars.push(right.clone());
-                },
-                Assign(_, ref value) => vars.push(value.clone()),
-                DoMerge(ref bld, ref elem) => {
-                    vars.push(bld.clone());
-                    vars.push(elem.clone());
-                },
-                GetResult(_, ref value) => vars.push(value.clone()),
-                _ => {}
-            }   
-        }
-        for var in &vars {
-            if prog.funcs[func_id].locals.get(&var) == None {
-                prog.funcs[func_id].params.insert(var.clone(), env.get(&var).unwrap().clone());
-                closure.insert(var.clone());
-            }
-        }
         let mut inner_closure = HashSet::new();
         use self::Terminator::*;
         match block.terminator {
-            // TODO how do we get rid of unused variable warnings here?
             ParallelFor(ref pf) => {
                 sir_param_correction_helper(prog, pf.body, env, &mut inner_closure);
                 sir_param_correction_helper(prog, pf.cont, env, &mut inner_closure);
@@ -256,13 +358,19 @@ env: &mut HashMap<Symbol, Type>, closure: &mut HashSet<Symbol>) {
             JumpFunction(jump_func) => {
                 sir_param_correction_helper(prog, jump_func, env, &mut inner_closure);
             },
-            _ => {}       
+            _ => {}
         }
-        for var in inner_closure {
-            if prog.funcs[func_id].locals.get(&var) == None {
-                prog.funcs[func_id].params.insert(var.clone(), env.get(&var).unwrap().clone());
-                closure.insert(var.clone());
-            }
+        if !inner_closure.is_empty() {
+            extra_uses.insert(block.id, inner_closure.into_iter().collect());
+        }
+    }
+
+    let live_in = compute_live_in(&prog.funcs[func_id], &extra_uses);
+    let entry_live = live_in.into_iter().next().unwrap_or_else(HashSet::new);
+    for var in entry_live {
+        if prog.funcs[func_id].locals.get(&var) == None && prog.funcs[func_id].params.get(&var) == None {
+            prog.funcs[func_id].params.insert(var.clone(), env.get(&var).unwrap().clone());
+            closure.insert(var);
         }
     }
 }
@@ -277,7 +385,7 @@ fn sir_param_correction(prog: &mut SirProgram) -> WeldResult<()> {
     let ref func = prog.funcs[0];
     for name in closure {
         if func.params.get(&name) == None {
-            weld_err!("Unbound symbol {}#{}", name.name, name.id)?;
+            weld_err!("Unbound symbol {}#{}", name.name, name.id)?
         }
     }
     Ok(())
@@ -295,12 +403,166 @@ pub fn ast_to_sir(expr: &TypedExpr) -> WeldResult<SirProgram> {
         let (res_func, res_block, res_sym) = gen_expr(body, &mut prog, 0, first_block)?;
         prog.funcs[res_func].blocks[res_block].terminator = Terminator::ProgramReturn(res_sym);
         sir_param_correction(&mut prog)?;
+        insert_frees(&mut prog);
+        verify_sir(&prog)?;
         Ok((prog))
     } else {
         weld_err!("Expression passed to ast_to_sir was not a Lambda")
     }
 }
 
+fn is_builder_type(ty: &Type) -> bool {
+    match *ty {
+        Type::Builder(_) => true,
+        _ => false
+    }
+}
+
+/// Insert `Free` statements for every builder created in the program once it is no longer live,
+/// so that builder storage doesn't outlive its last use. This is a post-pass over `ast_to_sir`'s
+/// output (run after `sir_param_correction`, so closures are already settled) that plays the role
+/// of a drop-scope pass in MIR lowering: every builder-typed local of a function (a `CreateBuilder`
+/// output, or a `Let`/`If` copy of one) other than a loop body's builder argument registers its
+/// symbol in that function's scope, and that registration is popped -- emitting a `Free` -- after
+/// the builder's last use, found by scanning the function's blocks in block order. Where uses sit
+/// on different branches, only the branch holding that last use gets a `Free`.
+fn insert_frees(prog: &mut SirProgram) {
+    for func_id in 0..prog.funcs.len() {
+        let scope: Vec<Symbol> = prog.funcs[func_id].locals.iter()
+            .filter(|&(_, ty)| is_builder_type(ty))
+            .map(|(sym, _)| sym.clone())
+            .collect();
+        // The value produced by this function's ProgramReturn (if any) escapes the function
+        // entirely and must never be freed here.
+        let returned = prog.funcs[func_id].blocks.iter().filter_map(|b| {
+            match b.terminator {
+                Terminator::ProgramReturn(ref sym) => Some(sym.clone()),
+                _ => None
+            }
+        }).next();
+        // A loop body's builder argument is the builder handed to the `ParallelFor`, which must
+        // stay live through the loop's `cont` function, so the body never frees it.
+        let loop_builder_args: HashSet<Symbol> = prog.funcs.iter()
+            .flat_map(|f| f.blocks.iter())
+            .filter_map(|b| match b.terminator {
+                Terminator::ParallelFor(ref pf) if pf.body == func_id => Some(pf.builder_arg.clone()),
+                _ => None
+            })
+            .collect();
+
+        // `locals` is a HashMap, so this order is arbitrary -- each builder is freed
+        // independently of the others, so there's no ordering requirement to uphold here.
+        for sym in scope {
+            if Some(&sym) == returned.as_ref() || loop_builder_args.contains(&sym) {
+                continue;
+            }
+            free_builder_after_last_use(prog, func_id, &sym);
+        }
+    }
+}
+
+/// Does `sym` (or a copy of it made via `Assign`, or a struct it's packed into via `MakeStruct`,
+/// within `func_id`) flow into a `ProgramReturn`, either directly in `func_id` or, transitively, in
+/// a function `func_id` hands it to via `JumpFunction`? Used to recognize a builder's `GetResult`
+/// output that aliases the returned value -- for builders whose result is the underlying buffer
+/// itself, freeing the builder after such a `GetResult` would free memory the caller still holds
+/// onto, whether it's returned directly or nested inside a returned struct.
+fn transitively_returns(prog: &SirProgram, func_id: FunctionId, sym: &Symbol) -> bool {
+    let mut aliases: HashSet<Symbol> = HashSet::new();
+    aliases.insert(sym.clone());
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for block in &prog.funcs[func_id].blocks {
+            for stmt in &block.statements {
+                match *stmt {
+                    Statement::Assign(ref out, ref value) => {
+                        if aliases.contains(value) && !aliases.contains(out) {
+                            aliases.insert(out.clone());
+                            changed = true;
+                        }
+                    },
+                    Statement::MakeStruct(ref out, ref elems) => {
+                        if elems.iter().any(|e| aliases.contains(e)) && !aliases.contains(out) {
+                            aliases.insert(out.clone());
+                            changed = true;
+                        }
+                    },
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    for block in &prog.funcs[func_id].blocks {
+        match block.terminator {
+            Terminator::ProgramReturn(ref ret_sym) if aliases.contains(ret_sym) => return true,
+            Terminator::JumpFunction(target) => {
+                for alias in aliases.iter().filter(|a| prog.funcs[target].params.contains_key(*a)) {
+                    if transitively_returns(prog, target, alias) {
+                        return true;
+                    }
+                }
+            },
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Find the last use of `sym` as a builder within `func_id` -- a `DoMerge`, a `GetResult`, or use
+/// as the builder argument of a `ParallelFor` -- and emit a `Free` immediately after it. A builder
+/// handed to a `ParallelFor` must stay live through the loop's `cont` function (the loop may still
+/// be merging into it across iterations), so when the last use found is a `ParallelFor`, the
+/// search continues into `cont` instead of freeing here. If the last use is a `GetResult` whose
+/// output escapes via `ProgramReturn` (directly or through further `JumpFunction`s), the builder
+/// is left live instead of freed, since its result may alias the builder's own storage.
+fn free_builder_after_last_use(prog: &mut SirProgram, func_id: FunctionId, sym: &Symbol) {
+    let mut last_use: Option<(BasicBlockId, usize)> = None;
+    let mut last_use_result: Option<Symbol> = None;
+    let mut handed_to_cont: Option<FunctionId> = None;
+
+    for block in &prog.funcs[func_id].blocks {
+        for (idx, stmt) in block.statements.iter().enumerate() {
+            match *stmt {
+                Statement::DoMerge(ref bld, _) if bld == sym => {
+                    last_use = Some((block.id, idx));
+                    last_use_result = None;
+                    handed_to_cont = None;
+                },
+                Statement::GetResult(ref out, ref bld) if bld == sym => {
+                    last_use = Some((block.id, idx));
+                    last_use_result = Some(out.clone());
+                    handed_to_cont = None;
+                },
+                _ => {}
+            }
+        }
+        if let Terminator::ParallelFor(ref pf) = block.terminator {
+            if &pf.builder == sym {
+                last_use = None;
+                last_use_result = None;
+                handed_to_cont = Some(pf.cont);
+            }
+        }
+    }
+
+    if let Some(cont_func) = handed_to_cont {
+        free_builder_after_last_use(prog, cont_func, sym);
+        return;
+    }
+
+    if let Some(ref out) = last_use_result {
+        if transitively_returns(prog, func_id, out) {
+            return;
+        }
+    }
+
+    if let Some((block_id, idx)) = last_use {
+        prog.funcs[func_id].blocks[block_id].statements.insert(idx + 1, Statement::Free(sym.clone()));
+    }
+}
+
 /// Generate code to compute the expression `expr` starting at the current tail of `cur_block`,
 /// possibly creating new basic blocks and functions in the process. Return the function and
 /// basic block that the expression will be ready in, and its symbol therein.
@@ -371,8 +633,7 @@ fn gen_expr(
         Merge { ref builder, ref value } => {
             let (cur_func, cur_block, builder_sym) = gen_expr(builder, prog, cur_func, cur_block)?;
             let (cur_func, cur_block, elem_sym) = gen_expr(value, prog, cur_func, cur_block)?;
-            prog.funcs[cur_func].blocks[cur_block].add_statement(DoMerge(builder_sym.clone(),
-                elem_sym));
+            prog.funcs[cur_func].blocks[cur_block].add_statement(DoMerge(builder_sym.clone(), elem_sym));
             Ok((cur_func, cur_block, builder_sym))
         },
 
@@ -426,6 +687,252 @@ fn gen_expr(
             }
         },
 
+        MakeStruct { ref elems } => {
+            let mut cur_func = cur_func;
+            let mut cur_block = cur_block;
+            let mut elem_syms = vec![];
+            for elem in elems {
+                let (next_func, next_block, elem_sym) = gen_expr(elem, prog, cur_func, cur_block)?;
+                cur_func = next_func;
+                cur_block = next_block;
+                elem_syms.push(elem_sym);
+            }
+            let res_sym = prog.add_local(&expr.ty, cur_func);
+            prog.funcs[cur_func].blocks[cur_block].add_statement(MakeStruct(res_sym.clone(), elem_syms));
+            Ok((cur_func, cur_block, res_sym))
+        },
+
+        GetField { expr: ref struct_expr, index } => {
+            let (cur_func, cur_block, value_sym) = gen_expr(struct_expr, prog, cur_func, cur_block)?;
+            let field_count = match struct_expr.ty {
+                Type::Struct(ref fields) => fields.len(),
+                ref other => weld_err!("GetField on non-struct type {}", print_type(other))?
+            };
+            if index as usize >= field_count {
+                weld_err!("GetField index {} out of bounds for struct with {} fields",
+                    index, field_count)?;
+            }
+            let res_sym = prog.add_local(&expr.ty, cur_func);
+            prog.funcs[cur_func].blocks[cur_block].add_statement(GetField(res_sym.clone(), value_sym, index));
+            Ok((cur_func, cur_block, res_sym))
+        },
+
         _ => weld_err!("Unsupported expression: {}", print_expr(expr))
     }
+}
+
+/// Look up `sym`'s type in a function-local symbol environment built from its `params` and
+/// `locals`, failing loudly if the symbol isn't bound -- a SIR-generation bug rather than
+/// something a Weld program author can trigger.
+fn lookup_sym_type(env: &HashMap<Symbol, Type>, sym: &Symbol, func_id: FunctionId) -> WeldResult<Type> {
+    match env.get(sym) {
+        Some(ty) => Ok(ty.clone()),
+        None => weld_err!("Unbound symbol {} in F{}", sym, func_id)
+    }
+}
+
+/// Verify that a generated `SirProgram` is internally well-typed. This walks every function,
+/// checking that each statement's operand and result types agree with the function's declared
+/// `params`/`locals`, that builder operations agree with the builder's merge/result type, that
+/// `Branch` conditions are boolean, and that every function referenced by a `JumpFunction` or
+/// `ParallelFor` exists. It does not re-run type inference; it only checks that SIR generation
+/// didn't lose or garble the types type inference already assigned to the AST.
+pub fn verify_sir(prog: &SirProgram) -> WeldResult<()> {
+    for (func_id, func) in prog.funcs.iter().enumerate() {
+        let mut env: HashMap<Symbol, Type> = HashMap::new();
+        for (sym, ty) in func.params.iter().chain(func.locals.iter()) {
+            env.insert(sym.clone(), ty.clone());
+        }
+
+        for block in &func.blocks {
+            for stmt in &block.statements {
+                verify_statement(&env, func_id, block.id, stmt)?;
+            }
+            verify_terminator(prog, &env, func_id, block.id, &block.terminator)?;
+        }
+    }
+    Ok(())
+}
+
+fn verify_statement(env: &HashMap<Symbol, Type>, func_id: FunctionId, block_id: BasicBlockId,
+stmt: &Statement) -> WeldResult<()> {
+    use self::Statement::*;
+    match *stmt {
+        AssignBinOp(ref out, kind, ref ty, ref left, ref right) => {
+            let left_ty = lookup_sym_type(env, left, func_id)?;
+            let right_ty = lookup_sym_type(env, right, func_id)?;
+            if left_ty != *ty || right_ty != *ty {
+                return weld_err!(
+                    "F{} B{}: AssignBinOp {} expects operands of type {} but got {} and {}",
+                    func_id, block_id, out, print_type(ty), print_type(&left_ty), print_type(&right_ty));
+            }
+            let out_ty = lookup_sym_type(env, out, func_id)?;
+            let expected_out_ty = if kind.is_comparison() { Type::Scalar(ScalarKind::Bool) } else { ty.clone() };
+            if out_ty != expected_out_ty {
+                return weld_err!(
+                    "F{} B{}: AssignBinOp result {} has type {} but expected {}",
+                    func_id, block_id, out, print_type(&out_ty), print_type(&expected_out_ty));
+            }
+        },
+
+        Assign(ref out, ref value) => {
+            let out_ty = lookup_sym_type(env, out, func_id)?;
+            let value_ty = lookup_sym_type(env, value, func_id)?;
+            if out_ty != value_ty {
+                return weld_err!(
+                    "F{} B{}: Assign {} has type {} but source {} has type {}",
+                    func_id, block_id, out, print_type(&out_ty), value, print_type(&value_ty));
+            }
+        },
+
+        DoMerge(ref bld, ref elem) => {
+            let bld_ty = lookup_sym_type(env, bld, func_id)?;
+            let elem_ty = lookup_sym_type(env, elem, func_id)?;
+            let merge_ty = match bld_ty {
+                Type::Builder(ref bk) => bk.merge_type(),
+                ref other => {
+                    return weld_err!("F{} B{}: merge target {} is not a builder (found {})",
+                        func_id, block_id, bld, print_type(other));
+                }
+            };
+            if elem_ty != merge_ty {
+                return weld_err!(
+                    "F{} B{}: merge into {} expects element of type {} but got {}",
+                    func_id, block_id, bld, print_type(&merge_ty), print_type(&elem_ty));
+            }
+        },
+
+        GetResult(ref out, ref bld) => {
+            let bld_ty = lookup_sym_type(env, bld, func_id)?;
+            let res_ty = match bld_ty {
+                Type::Builder(ref bk) => bk.result_type(),
+                ref other => {
+                    return weld_err!("F{} B{}: result of {} is not a builder (found {})",
+                        func_id, block_id, bld, print_type(other));
+                }
+            };
+            let out_ty = lookup_sym_type(env, out, func_id)?;
+            if out_ty != res_ty {
+                return weld_err!(
+                    "F{} B{}: result {} has type {} but builder {} yields {}",
+                    func_id, block_id, out, print_type(&out_ty), bld, print_type(&res_ty));
+            }
+        },
+
+        CreateBuilder(ref out, ref ty) => {
+            let out_ty = lookup_sym_type(env, out, func_id)?;
+            if out_ty != *ty {
+                return weld_err!(
+                    "F{} B{}: new builder {} has declared type {} but statement creates {}",
+                    func_id, block_id, out, print_type(&out_ty), print_type(ty));
+            }
+        },
+
+        Free(ref sym) => {
+            lookup_sym_type(env, sym, func_id)?;
+        },
+
+        AssignLiteral(ref out, _) => {
+            lookup_sym_type(env, out, func_id)?;
+        },
+
+        MakeStruct(ref out, ref elems) => {
+            let out_ty = lookup_sym_type(env, out, func_id)?;
+            let mut field_tys = vec![];
+            for elem in elems {
+                field_tys.push(lookup_sym_type(env, elem, func_id)?);
+            }
+            let expected_ty = Type::Struct(field_tys);
+            if out_ty != expected_ty {
+                return weld_err!(
+                    "F{} B{}: MakeStruct {} has type {} but its fields compose to {}",
+                    func_id, block_id, out, print_type(&out_ty), print_type(&expected_ty));
+            }
+        },
+
+        GetField(ref out, ref value, index) => {
+            let value_ty = lookup_sym_type(env, value, func_id)?;
+            let field_ty = match value_ty {
+                Type::Struct(ref fields) => match fields.get(index as usize) {
+                    Some(ty) => ty.clone(),
+                    None => return weld_err!("F{} B{}: GetField index {} out of bounds on {}",
+                        func_id, block_id, index, value)
+                },
+                ref other => return weld_err!("F{} B{}: GetField on non-struct {} (found {})",
+                    func_id, block_id, value, print_type(other))
+            };
+            let out_ty = lookup_sym_type(env, out, func_id)?;
+            if out_ty != field_ty {
+                return weld_err!(
+                    "F{} B{}: GetField {} has type {} but field {} has type {}",
+                    func_id, block_id, out, print_type(&out_ty), index, print_type(&field_ty));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn verify_terminator(prog: &SirProgram, env: &HashMap<Symbol, Type>, func_id: FunctionId,
+block_id: BasicBlockId, term: &Terminator) -> WeldResult<()> {
+    use self::Terminator::*;
+    match *term {
+        Branch(ref cond, on_true, on_false) => {
+            let cond_ty = lookup_sym_type(env, cond, func_id)?;
+            if cond_ty != Type::Scalar(ScalarKind::Bool) {
+                return weld_err!("F{} B{}: branch condition {} has type {} but expected bool",
+                    func_id, block_id, cond, print_type(&cond_ty));
+            }
+            if on_true >= prog.funcs[func_id].blocks.len() || on_false >= prog.funcs[func_id].blocks.len() {
+                return weld_err!("F{} B{}: branch targets B{}/B{} do not exist",
+                    func_id, block_id, on_true, on_false);
+            }
+        },
+
+        JumpBlock(target) => {
+            if target >= prog.funcs[func_id].blocks.len() {
+                return weld_err!("F{} B{}: jump target B{} does not exist", func_id, block_id, target);
+            }
+        },
+
+        JumpFunction(target) => {
+            verify_callee_exists(prog, func_id, block_id, target)?;
+        },
+
+        ParallelFor(ref pf) => {
+            verify_callee_exists(prog, func_id, block_id, pf.body)?;
+            verify_callee_exists(prog, func_id, block_id, pf.cont)?;
+        },
+
+        ProgramReturn(ref sym) => {
+            lookup_sym_type(env, sym, func_id)?;
+        },
+
+        EndFunction | Crash => {}
+    }
+    Ok(())
+}
+
+/// Check that `target` names a real function in `prog` and that every symbol its body actually
+/// uses is bound in its own `params`/`locals` -- a callee must not silently rely on a symbol from
+/// its caller's environment that wasn't threaded through as a parameter. This only checks binding;
+/// `target`'s statements get fully type-checked when `verify_sir`'s outer loop reaches `target`
+/// directly, so doing that here too would just verify them twice.
+fn verify_callee_exists(prog: &SirProgram, caller_func: FunctionId, caller_block: BasicBlockId,
+target: FunctionId) -> WeldResult<()> {
+    if target >= prog.funcs.len() {
+        return weld_err!("F{} B{}: target function F{} does not exist",
+            caller_func, caller_block, target);
+    }
+    let callee = &prog.funcs[target];
+    for block in &callee.blocks {
+        let uses = block.statements.iter().flat_map(statement_uses)
+            .chain(terminator_local_uses(&block.terminator));
+        for sym in uses {
+            if callee.params.get(&sym) == None && callee.locals.get(&sym) == None {
+                return weld_err!("F{} B{}: F{} uses symbol {} not bound in its own params/locals",
+                    caller_func, caller_block, target, sym);
+            }
+        }
+    }
+    Ok(())
 }
\ No newline at end of file